@@ -1,20 +1,192 @@
+use std::collections::HashMap;
+
 use convert_case::{Case, Casing};
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
-use syn::{Data, DataEnum, DeriveInput, Fields};
+use quote::{quote, ToTokens};
+use syn::punctuated::Punctuated;
+use syn::{
+    Attribute, Data, DataEnum, DeriveInput, Expr, ExprLit, Fields, Lit, LitStr, Meta, Path, Token,
+};
+
+/// Container-level configuration read from `#[nice_enum(...)]` on the enum
+/// itself: `kind = "MyState"` renames the generated kind enum, and
+/// `derive(...)` adds extra derives to it on top of the built-in ones.
+#[derive(Default)]
+struct ContainerConfig {
+    kind_ident: Option<String>,
+    extra_derives: Vec<Path>,
+}
+
+/// Per-variant configuration read from `#[nice_enum(...)]` on a variant:
+/// `skip` suppresses all generated methods for the variant, `rename = "..."`
+/// overrides the snake_case stem used to name them.
+#[derive(Default)]
+struct VariantConfig {
+    skip: bool,
+    rename: Option<String>,
+}
+
+fn expect_lit_str(expr: &Expr) -> syn::Result<LitStr> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => Ok(s.clone()),
+        _ => Err(syn::Error::new_spanned(expr, "expected a string literal")),
+    }
+}
+
+fn nice_enum_attr_metas(attrs: &[Attribute]) -> syn::Result<Vec<Meta>> {
+    let mut metas = Vec::new();
+
+    for attr in attrs {
+        if !attr.path().is_ident("nice_enum") {
+            continue;
+        }
+
+        metas.extend(attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?);
+    }
+
+    Ok(metas)
+}
+
+fn parse_container_config(attrs: &[Attribute]) -> syn::Result<ContainerConfig> {
+    let mut config = ContainerConfig::default();
+
+    for meta in nice_enum_attr_metas(attrs)? {
+        match &meta {
+            Meta::NameValue(nv) if nv.path.is_ident("kind") => {
+                config.kind_ident = Some(expect_lit_str(&nv.value)?.value());
+            }
+            Meta::List(list) if list.path.is_ident("derive") => {
+                config.extra_derives.extend(
+                    list.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)?,
+                );
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &meta,
+                    "unknown `nice_enum` attribute, expected `kind = \"...\"` or `derive(...)`",
+                ))
+            }
+        }
+    }
+
+    Ok(config)
+}
+
+fn parse_variant_config(attrs: &[Attribute]) -> syn::Result<VariantConfig> {
+    let mut config = VariantConfig::default();
+
+    for meta in nice_enum_attr_metas(attrs)? {
+        match &meta {
+            Meta::Path(path) if path.is_ident("skip") => {
+                config.skip = true;
+            }
+            Meta::NameValue(nv) if nv.path.is_ident("rename") => {
+                config.rename = Some(expect_lit_str(&nv.value)?.value());
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &meta,
+                    "unknown `nice_enum` attribute, expected `skip` or `rename = \"...\"`",
+                ))
+            }
+        }
+    }
+
+    Ok(config)
+}
+
+// Builds `as_foo`/`as_foo_mut`/`into_foo`/`unwrap_foo`/`try_unwrap_foo` for a
+// data-carrying variant, given how to destructure it in a match arm, the
+// expression that binds its field(s) (bare for single-field variants, a
+// tuple for multi-field ones), and the ref/mut-ref/owned types it yields.
+#[allow(clippy::too_many_arguments)]
+fn build_accessors(
+    vis: syn::Visibility,
+    source_ident: &TokenStream,
+    ident_snake_case: &str,
+    destructure: &TokenStream,
+    bind_expr: &TokenStream,
+    ref_ty: TokenStream,
+    mut_ref_ty: TokenStream,
+    owned_ty: TokenStream,
+) -> (TokenStream, TokenStream, TokenStream, TokenStream, TokenStream) {
+    let as_method = Ident::new(&format!("as_{}", ident_snake_case), Span::call_site());
+    let as_mut_method = Ident::new(&format!("as_{}_mut", ident_snake_case), Span::call_site());
+    let into_method = Ident::new(&format!("into_{}", ident_snake_case), Span::call_site());
+    let unwrap_method = Ident::new(&format!("unwrap_{}", ident_snake_case), Span::call_site());
+    let try_unwrap_method = Ident::new(&format!("try_unwrap_{}", ident_snake_case), Span::call_site());
+
+    let as_method = quote! {
+        #vis fn #as_method(&self) -> Option<#ref_ty> {
+            match self {
+                #source_ident #destructure => Some(#bind_expr),
+                _ => None,
+            }
+        }
+    };
+
+    let as_mut_method = quote! {
+        #vis fn #as_mut_method(&mut self) -> Option<#mut_ref_ty> {
+            match self {
+                #source_ident #destructure => Some(#bind_expr),
+                _ => None,
+            }
+        }
+    };
+
+    let into_method = quote! {
+        #vis fn #into_method(self) -> Option<#owned_ty> {
+            match self {
+                #source_ident #destructure => Some(#bind_expr),
+                _ => None,
+            }
+        }
+    };
+
+    let panic_message = format!("called {} on a {{:?}} value", unwrap_method);
+    let unwrap_method = quote! {
+        #vis fn #unwrap_method(self) -> #owned_ty {
+            match self {
+                #source_ident #destructure => #bind_expr,
+                other => panic!(#panic_message, other.kind()),
+            }
+        }
+    };
+
+    let try_unwrap_method = quote! {
+        #vis fn #try_unwrap_method(self) -> Result<#owned_ty, Self> {
+            match self {
+                #source_ident #destructure => Ok(#bind_expr),
+                other => Err(other),
+            }
+        }
+    };
+
+    (as_method, as_mut_method, into_method, unwrap_method, try_unwrap_method)
+}
+
+fn nice_enum_impl(input: DeriveInput) -> syn::Result<TokenStream> {
+    let container_config = parse_container_config(&input.attrs)?;
 
-fn nice_enum_impl(input: DeriveInput) -> TokenStream {
     let input_variants = match input.data {
         Data::Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("NiceEnum can only be derived for enums"),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "NiceEnum can only be derived for enums",
+            ))
+        }
     };
 
     let vis = input.vis;
     let source_ident = input.ident.clone();
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-    let kind_ident_name = format!("{}Kind", input.ident);
+    let kind_ident_name = container_config
+        .kind_ident
+        .unwrap_or_else(|| format!("{}Kind", input.ident));
     let kind_ident = Ident::new(&kind_ident_name, Span::call_site());
+    let extra_derives = container_config.extra_derives;
 
     struct Variant {
         ident: syn::Ident,
@@ -22,94 +194,227 @@ fn nice_enum_impl(input: DeriveInput) -> TokenStream {
         as_method: Option<TokenStream>,
         as_mut_method: Option<TokenStream>,
         into_method: Option<TokenStream>,
-        is_variant_method: syn::Ident,
+        unwrap_method: Option<TokenStream>,
+        try_unwrap_method: Option<TokenStream>,
+        ctor_fn: Option<TokenStream>,
+        // The inner type of a single-field unnamed variant, kept around so a
+        // `From<T>` impl can be emitted for it once we know it's not shared
+        // with another variant.
+        single_field_ty: Option<syn::Type>,
+        is_variant_method: Option<syn::Ident>,
         source_arm: TokenStream,
     }
 
+    // The shape of a variant's fields, reduced to what the accessor/unwrap
+    // methods below need: how to destructure it in a match arm, and the
+    // (possibly tupled) ref/mut-ref/owned types it yields.
+    enum FieldShape {
+        Unit,
+        // A single-field variant, named or unnamed. `field_ident` is the
+        // variant's own field name for a named variant (used in place of the
+        // synthetic `v`), or `None` for an unnamed one.
+        Single { ty: syn::Type, field_ident: Option<Ident>, named: bool },
+        Multi { idents: Vec<Ident>, tys: Vec<syn::Type>, named: bool },
+    }
+
     let variants: Vec<Variant> = input_variants
         .into_iter()
         .map(|variant| {
+            let variant_config = parse_variant_config(&variant.attrs)?;
+
             let mut ident = variant.ident.clone();
             ident.set_span(Span::call_site());
 
             let ident_str = ident.clone().to_string();
-            let ident_snake_case = ident_str.to_case(Case::Snake);
+            let ident_snake_case = variant_config
+                .rename
+                .clone()
+                .unwrap_or_else(|| ident_str.to_case(Case::Snake));
 
             let qualified = quote! { #kind_ident::#ident };
 
-            let is_variant_method = format!("is_{}", &ident_snake_case);
-            let is_variant_method = Ident::new(&is_variant_method, Span::call_site());
+            let is_variant_method = if variant_config.skip {
+                None
+            } else {
+                let is_variant_method = format!("is_{}", &ident_snake_case);
+                Some(Ident::new(&is_variant_method, Span::call_site()))
+            };
 
             let source_ident = quote! { Self::#ident };
 
-            let (source_arm, as_method, as_mut_method, into_method) = match &variant.fields {
-                Fields::Named(_) => (quote! { #source_ident { .. } }, None, None, None),
-                Fields::Unnamed(fields) => {
-                    if fields.unnamed.len() == 1 {
-                        // SAFETY: We know that there is exactly one field in the variant.
-                        let inner = fields.unnamed.first().unwrap();
-                        let inner_ty = &inner.ty;
+            let shape = match &variant.fields {
+                Fields::Unit => FieldShape::Unit,
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                    // SAFETY: We know that there is exactly one field in the variant.
+                    let inner = fields.unnamed.first().unwrap();
+                    FieldShape::Single { ty: inner.ty.clone(), field_ident: None, named: false }
+                }
+                Fields::Unnamed(fields) => FieldShape::Multi {
+                    idents: (0..fields.unnamed.len())
+                        .map(|i| Ident::new(&format!("v{}", i), Span::call_site()))
+                        .collect(),
+                    tys: fields.unnamed.iter().map(|f| f.ty.clone()).collect(),
+                    named: false,
+                },
+                Fields::Named(fields) if fields.named.len() == 1 => {
+                    // SAFETY: We know that there is exactly one field in the variant.
+                    let inner = fields.named.first().unwrap();
+                    FieldShape::Single {
+                        ty: inner.ty.clone(),
+                        field_ident: Some(inner.ident.clone().unwrap()),
+                        named: true,
+                    }
+                }
+                Fields::Named(fields) => FieldShape::Multi {
+                    idents: fields
+                        .named
+                        .iter()
+                        .map(|f| f.ident.clone().unwrap())
+                        .collect(),
+                    tys: fields.named.iter().map(|f| f.ty.clone()).collect(),
+                    named: true,
+                },
+            };
+
+            let source_arm = match &shape {
+                FieldShape::Unit => quote! { #source_ident },
+                FieldShape::Single { named: false, .. } => quote! { #source_ident(..) },
+                FieldShape::Single { named: true, .. } => quote! { #source_ident { .. } },
+                FieldShape::Multi { named: true, .. } => quote! { #source_ident { .. } },
+                FieldShape::Multi { named: false, .. } => quote! { #source_ident(..) },
+            };
+
+            let ctor_ident = Ident::new(&ident_snake_case, Span::call_site());
 
-                        let as_method = format!("as_{}", &ident_snake_case);
-                        let as_method = Ident::new(&as_method, Span::call_site());
+            let (as_method, as_mut_method, into_method, unwrap_method, try_unwrap_method, ctor_fn, single_field_ty) =
+                match &shape {
+                    FieldShape::Unit => {
+                        let try_unwrap_method = format!("try_unwrap_{}", &ident_snake_case);
+                        let try_unwrap_method = Ident::new(&try_unwrap_method, Span::call_site());
 
-                        let as_method = quote! {
-                            #vis fn #as_method(&self) -> Option<&#inner_ty> {
+                        let try_unwrap_method = quote! {
+                            #vis fn #try_unwrap_method(self) -> Result<(), Self> {
                                 match self {
-                                    #source_ident(v) => Some(v),
-                                    _ => None,
+                                    #source_ident => Ok(()),
+                                    other => Err(other),
                                 }
                             }
                         };
 
-                        let as_mut_method = format!("as_{}_mut", &ident_snake_case);
-                        let as_mut_method = Ident::new(&as_mut_method, Span::call_site());
+                        (None, None, None, None, Some(try_unwrap_method), None, None)
+                    }
+                    FieldShape::Single { ty, field_ident, named } => {
+                        let bind_ident = field_ident
+                            .clone()
+                            .unwrap_or_else(|| Ident::new("v", Span::call_site()));
+                        let destructure = if *named {
+                            quote! { { #bind_ident } }
+                        } else {
+                            quote! { (#bind_ident) }
+                        };
+                        let bind_expr = quote! { #bind_ident };
+
+                        let (as_method, as_mut_method, into_method, unwrap_method, try_unwrap_method) =
+                            build_accessors(
+                                vis.clone(),
+                                &source_ident,
+                                &ident_snake_case,
+                                &destructure,
+                                &bind_expr,
+                                quote! { &#ty },
+                                quote! { &mut #ty },
+                                quote! { #ty },
+                            );
 
-                        let as_mut_method = quote! {
-                            #vis fn #as_mut_method(&mut self) -> Option<&mut #inner_ty> {
-                                match self {
-                                    #source_ident(v) => Some(v),
-                                    _ => None,
+                        let ctor_fn = if *named {
+                            quote! {
+                                #vis fn #ctor_ident(#bind_ident: #ty) -> Self {
+                                    #source_ident { #bind_ident }
+                                }
+                            }
+                        } else {
+                            quote! {
+                                #vis fn #ctor_ident(#bind_ident: #ty) -> Self {
+                                    #source_ident(#bind_ident)
                                 }
                             }
                         };
 
-                        let into_method = format!("into_{}", &ident_snake_case);
-                        let into_method = Ident::new(&into_method, Span::call_site());
+                        // `From<T>` is only emitted for unnamed newtype variants
+                        // (see `source_from_impls` below): `Self::Variant(v)`
+                        // doesn't work for a named single-field variant.
+                        let single_field_ty = if *named { None } else { Some(ty.clone()) };
 
-                        let into_method = quote! {
-                            #vis fn #into_method(self) -> Option<#inner_ty> {
-                                match self {
-                                    #source_ident(v) => Some(v),
-                                    _ => None,
-                                }
+                        (
+                            Some(as_method),
+                            Some(as_mut_method),
+                            Some(into_method),
+                            Some(unwrap_method),
+                            Some(try_unwrap_method),
+                            Some(ctor_fn),
+                            single_field_ty,
+                        )
+                    }
+                    FieldShape::Multi { idents, tys, named } => {
+                        let destructure = if *named {
+                            quote! { { #(#idents),* } }
+                        } else {
+                            quote! { ( #(#idents),* ) }
+                        };
+                        let bind_expr = quote! { ( #(#idents,)* ) };
+
+                        let (as_method, as_mut_method, into_method, unwrap_method, try_unwrap_method) =
+                            build_accessors(
+                                vis.clone(),
+                                &source_ident,
+                                &ident_snake_case,
+                                &destructure,
+                                &bind_expr,
+                                quote! { (#(&#tys,)*) },
+                                quote! { (#(&mut #tys,)*) },
+                                quote! { (#(#tys,)*) },
+                            );
+
+                        let ctor_fn = quote! {
+                            #vis fn #ctor_ident(#(#idents: #tys),*) -> Self {
+                                #source_ident #destructure
                             }
                         };
 
                         (
-                            quote! { #source_ident(_) },
                             Some(as_method),
                             Some(as_mut_method),
                             Some(into_method),
+                            Some(unwrap_method),
+                            Some(try_unwrap_method),
+                            Some(ctor_fn),
+                            None,
                         )
-                    } else {
-                        (quote! { #source_ident(_) }, None, None, None)
                     }
-                }
-                Fields::Unit => (quote! { #source_ident }, None, None, None),
-            };
+                };
+
+            let (as_method, as_mut_method, into_method, unwrap_method, try_unwrap_method, ctor_fn, single_field_ty) =
+                if variant_config.skip {
+                    (None, None, None, None, None, None, None)
+                } else {
+                    (as_method, as_mut_method, into_method, unwrap_method, try_unwrap_method, ctor_fn, single_field_ty)
+                };
 
-            Variant {
+            Ok(Variant {
                 ident,
                 qualified,
                 as_method,
                 as_mut_method,
                 into_method,
+                unwrap_method,
+                try_unwrap_method,
+                ctor_fn,
+                single_field_ty,
                 is_variant_method,
                 source_arm,
-            }
+            })
         })
-        .collect();
+        .collect::<syn::Result<Vec<_>>>()?;
 
     let enum_kind_body: TokenStream = variants
         .iter()
@@ -119,8 +424,62 @@ fn nice_enum_impl(input: DeriveInput) -> TokenStream {
         })
         .collect();
 
+    // Reflection over the Kind enum: every kind is unit-only and `Copy`, so
+    // listing/naming/round-tripping it through a string is cheap to emit.
+    let kind_variant_idents: Vec<&Ident> = variants.iter().map(|variant| &variant.ident).collect();
+    let kind_variant_names: Vec<String> =
+        variants.iter().map(|variant| variant.ident.to_string()).collect();
+
+    let kind_parse_error_ident = Ident::new(&format!("{}ParseError", kind_ident), Span::call_site());
+
+    let kind_reflection_impl = quote! {
+        impl #kind_ident {
+            #vis const ALL: &'static [#kind_ident] = &[#(#kind_ident::#kind_variant_idents),*];
+
+            #vis fn name(&self) -> &'static str {
+                match self {
+                    #(#kind_ident::#kind_variant_idents => #kind_variant_names,)*
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for #kind_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+
+        #[derive(Debug)]
+        #vis struct #kind_parse_error_ident(String);
+
+        impl ::core::fmt::Display for #kind_parse_error_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "unknown `{}` variant: `{}`", stringify!(#kind_ident), self.0)
+            }
+        }
+
+        impl ::core::error::Error for #kind_parse_error_ident {}
+
+        impl ::core::str::FromStr for #kind_ident {
+            type Err = #kind_parse_error_ident;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #(#kind_variant_names => Ok(#kind_ident::#kind_variant_idents),)*
+                    _ => Err(#kind_parse_error_ident(s.to_string())),
+                }
+            }
+        }
+    };
+
+    let kind_derive_attr = if extra_derives.is_empty() {
+        quote! { #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)] }
+    } else {
+        quote! { #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, #(#extra_derives),*)] }
+    };
+
     let enum_kind_impl = quote! {
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #kind_derive_attr
         #vis enum #kind_ident {
             #enum_kind_body
         }
@@ -145,15 +504,15 @@ fn nice_enum_impl(input: DeriveInput) -> TokenStream {
 
     let source_is_variant_fn: TokenStream = variants
         .iter()
-        .map(|variant| {
-            let method = &variant.is_variant_method;
+        .filter_map(|variant| {
+            let method = variant.is_variant_method.as_ref()?;
             let qualified = &variant.qualified;
 
-            quote! {
+            Some(quote! {
                 #vis fn #method(&self) -> bool {
                     matches!(self.kind(), #qualified)
                 }
-            }
+            })
         })
         .collect();
 
@@ -173,9 +532,58 @@ fn nice_enum_impl(input: DeriveInput) -> TokenStream {
         .filter_map(|variant| variant.into_method.clone())
         .collect();
 
-    quote! {
+    let source_unwrap_variant_fn: TokenStream = variants
+        .iter()
+        .filter_map(|variant| variant.unwrap_method.clone())
+        .collect();
+
+    let source_try_unwrap_variant_fn: TokenStream = variants
+        .iter()
+        .filter_map(|variant| variant.try_unwrap_method.clone())
+        .collect();
+
+    let source_ctor_fn: TokenStream = variants
+        .iter()
+        .filter_map(|variant| variant.ctor_fn.clone())
+        .collect();
+
+    // Only emit `From<T>` for inner types that are unique across the
+    // variants; a type shared by two variants would need two conflicting
+    // `impl From<T>` blocks, so both are skipped.
+    let mut single_field_ty_counts: HashMap<String, usize> = HashMap::new();
+    for variant in &variants {
+        if let Some(ty) = &variant.single_field_ty {
+            *single_field_ty_counts
+                .entry(ty.to_token_stream().to_string())
+                .or_default() += 1;
+        }
+    }
+
+    let source_from_impls: TokenStream = variants
+        .iter()
+        .filter_map(|variant| {
+            let ty = variant.single_field_ty.as_ref()?;
+            if single_field_ty_counts[&ty.to_token_stream().to_string()] != 1 {
+                return None;
+            }
+
+            let ident = &variant.ident;
+
+            Some(quote! {
+                impl #impl_generics ::core::convert::From<#ty> for #source_ident #ty_generics #where_clause {
+                    fn from(v: #ty) -> Self {
+                        Self::#ident(v)
+                    }
+                }
+            })
+        })
+        .collect();
+
+    Ok(quote! {
         #enum_kind_impl
 
+        #kind_reflection_impl
+
         impl #impl_generics #source_ident #ty_generics #where_clause {
             #source_kind_fn
 
@@ -186,15 +594,25 @@ fn nice_enum_impl(input: DeriveInput) -> TokenStream {
             #source_as_mut_variant_fn
 
             #source_into_variant_fn
+
+            #source_unwrap_variant_fn
+
+            #source_try_unwrap_variant_fn
+
+            #source_ctor_fn
         }
-    }
+
+        #source_from_impls
+    })
 }
 
-#[proc_macro_derive(NiceEnum)]
+#[proc_macro_derive(NiceEnum, attributes(nice_enum))]
 pub fn derive_struct_info(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse_macro_input!(input as DeriveInput);
 
-    nice_enum_impl(input).into()
+    nice_enum_impl(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 #[cfg(test)]
@@ -213,7 +631,7 @@ mod tests {
             }
         };
 
-        let actual_tokens = nice_enum_impl(ast);
+        let actual_tokens = nice_enum_impl(ast).unwrap();
 
         let expected_tokens = quote! {
             #[derive(Debug , Clone , Copy , PartialEq , Eq , PartialOrd , Ord , Hash)]
@@ -223,12 +641,54 @@ mod tests {
                 UnnamedFields,
             }
 
+            impl MyEnumKind {
+                pub const ALL: &'static [MyEnumKind] = &[MyEnumKind::Unit, MyEnumKind::NamedFields, MyEnumKind::UnnamedFields];
+
+                pub fn name(&self) -> &'static str {
+                    match self {
+                        MyEnumKind::Unit => "Unit",
+                        MyEnumKind::NamedFields => "NamedFields",
+                        MyEnumKind::UnnamedFields => "UnnamedFields",
+                    }
+                }
+            }
+
+            impl ::core::fmt::Display for MyEnumKind {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(self.name())
+                }
+            }
+
+            #[derive(Debug)]
+            pub struct MyEnumKindParseError(String);
+
+            impl ::core::fmt::Display for MyEnumKindParseError {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "unknown `{}` variant: `{}`", stringify!(MyEnumKind), self.0)
+                }
+            }
+
+            impl ::core::error::Error for MyEnumKindParseError {}
+
+            impl ::core::str::FromStr for MyEnumKind {
+                type Err = MyEnumKindParseError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        "Unit" => Ok(MyEnumKind::Unit),
+                        "NamedFields" => Ok(MyEnumKind::NamedFields),
+                        "UnnamedFields" => Ok(MyEnumKind::UnnamedFields),
+                        _ => Err(MyEnumKindParseError(s.to_string())),
+                    }
+                }
+            }
+
             impl MyEnum {
                 pub fn kind(&self) -> MyEnumKind {
                     match self {
                         Self::Unit => MyEnumKind::Unit,
                         Self::NamedFields { .. } => MyEnumKind::NamedFields,
-                        Self::UnnamedFields(_) => MyEnumKind::UnnamedFields,
+                        Self::UnnamedFields(..) => MyEnumKind::UnnamedFields,
                     }
                 }
 
@@ -244,6 +704,13 @@ mod tests {
                     matches!(self.kind(), MyEnumKind::UnnamedFields)
                 }
 
+                pub fn as_named_fields(&self) -> Option<&u32> {
+                    match self {
+                        Self::NamedFields { a } => Some(a),
+                        _ => None,
+                    }
+                }
+
                 pub fn as_unnamed_fields(&self) -> Option<&u32> {
                     match self {
                         Self::UnnamedFields(v) => Some(v),
@@ -251,6 +718,13 @@ mod tests {
                     }
                 }
 
+                pub fn as_named_fields_mut(&mut self) -> Option<&mut u32> {
+                    match self {
+                        Self::NamedFields { a } => Some(a),
+                        _ => None,
+                    }
+                }
+
                 pub fn as_unnamed_fields_mut(&mut self) -> Option<&mut u32> {
                     match self {
                         Self::UnnamedFields(v) => Some(v),
@@ -258,15 +732,777 @@ mod tests {
                     }
                 }
 
+                pub fn into_named_fields(self) -> Option<u32> {
+                    match self {
+                        Self::NamedFields { a } => Some(a),
+                        _ => None,
+                    }
+                }
+
                 pub fn into_unnamed_fields(self) -> Option<u32> {
                     match self {
                         Self::UnnamedFields(v) => Some(v),
                         _ => None,
                     }
                 }
+
+                pub fn unwrap_named_fields(self) -> u32 {
+                    match self {
+                        Self::NamedFields { a } => a,
+                        other => panic!("called unwrap_named_fields on a {:?} value", other.kind()),
+                    }
+                }
+
+                pub fn unwrap_unnamed_fields(self) -> u32 {
+                    match self {
+                        Self::UnnamedFields(v) => v,
+                        other => panic!("called unwrap_unnamed_fields on a {:?} value", other.kind()),
+                    }
+                }
+
+                pub fn try_unwrap_unit(self) -> Result<(), Self> {
+                    match self {
+                        Self::Unit => Ok(()),
+                        other => Err(other),
+                    }
+                }
+
+                pub fn try_unwrap_named_fields(self) -> Result<u32, Self> {
+                    match self {
+                        Self::NamedFields { a } => Ok(a),
+                        other => Err(other),
+                    }
+                }
+
+                pub fn try_unwrap_unnamed_fields(self) -> Result<u32, Self> {
+                    match self {
+                        Self::UnnamedFields(v) => Ok(v),
+                        other => Err(other),
+                    }
+                }
+
+                pub fn named_fields(a: u32) -> Self {
+                    Self::NamedFields { a }
+                }
+
+                pub fn unnamed_fields(v: u32) -> Self {
+                    Self::UnnamedFields(v)
+                }
+            }
+
+            impl ::core::convert::From<u32> for MyEnum {
+                fn from(v: u32) -> Self {
+                    Self::UnnamedFields(v)
+                }
             }
         };
 
         assert_eq!(actual_tokens.to_string(), expected_tokens.to_string());
     }
+
+    #[test]
+    fn test_derives_tuple_accessors_for_multi_field_variants() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                NamedMulti { a: u32, b: String },
+                UnnamedMulti(u32, String),
+            }
+        };
+
+        let actual_tokens = nice_enum_impl(ast).unwrap();
+
+        let expected_tokens = quote! {
+            #[derive(Debug , Clone , Copy , PartialEq , Eq , PartialOrd , Ord , Hash)]
+            pub enum MyEnumKind {
+                NamedMulti,
+                UnnamedMulti,
+            }
+
+            impl MyEnumKind {
+                pub const ALL: &'static [MyEnumKind] = &[MyEnumKind::NamedMulti, MyEnumKind::UnnamedMulti];
+
+                pub fn name(&self) -> &'static str {
+                    match self {
+                        MyEnumKind::NamedMulti => "NamedMulti",
+                        MyEnumKind::UnnamedMulti => "UnnamedMulti",
+                    }
+                }
+            }
+
+            impl ::core::fmt::Display for MyEnumKind {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(self.name())
+                }
+            }
+
+            #[derive(Debug)]
+            pub struct MyEnumKindParseError(String);
+
+            impl ::core::fmt::Display for MyEnumKindParseError {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "unknown `{}` variant: `{}`", stringify!(MyEnumKind), self.0)
+                }
+            }
+
+            impl ::core::error::Error for MyEnumKindParseError {}
+
+            impl ::core::str::FromStr for MyEnumKind {
+                type Err = MyEnumKindParseError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        "NamedMulti" => Ok(MyEnumKind::NamedMulti),
+                        "UnnamedMulti" => Ok(MyEnumKind::UnnamedMulti),
+                        _ => Err(MyEnumKindParseError(s.to_string())),
+                    }
+                }
+            }
+
+            impl MyEnum {
+                pub fn kind(&self) -> MyEnumKind {
+                    match self {
+                        Self::NamedMulti { .. } => MyEnumKind::NamedMulti,
+                        Self::UnnamedMulti(..) => MyEnumKind::UnnamedMulti,
+                    }
+                }
+
+                pub fn is_named_multi(&self) -> bool {
+                    matches!(self.kind(), MyEnumKind::NamedMulti)
+                }
+
+                pub fn is_unnamed_multi(&self) -> bool {
+                    matches!(self.kind(), MyEnumKind::UnnamedMulti)
+                }
+
+                pub fn as_named_multi(&self) -> Option<(&u32, &String,)> {
+                    match self {
+                        Self::NamedMulti { a, b } => Some((a, b,)),
+                        _ => None,
+                    }
+                }
+
+                pub fn as_unnamed_multi(&self) -> Option<(&u32, &String,)> {
+                    match self {
+                        Self::UnnamedMulti(v0, v1) => Some((v0, v1,)),
+                        _ => None,
+                    }
+                }
+
+                pub fn as_named_multi_mut(&mut self) -> Option<(&mut u32, &mut String,)> {
+                    match self {
+                        Self::NamedMulti { a, b } => Some((a, b,)),
+                        _ => None,
+                    }
+                }
+
+                pub fn as_unnamed_multi_mut(&mut self) -> Option<(&mut u32, &mut String,)> {
+                    match self {
+                        Self::UnnamedMulti(v0, v1) => Some((v0, v1,)),
+                        _ => None,
+                    }
+                }
+
+                pub fn into_named_multi(self) -> Option<(u32, String,)> {
+                    match self {
+                        Self::NamedMulti { a, b } => Some((a, b,)),
+                        _ => None,
+                    }
+                }
+
+                pub fn into_unnamed_multi(self) -> Option<(u32, String,)> {
+                    match self {
+                        Self::UnnamedMulti(v0, v1) => Some((v0, v1,)),
+                        _ => None,
+                    }
+                }
+
+                pub fn unwrap_named_multi(self) -> (u32, String,) {
+                    match self {
+                        Self::NamedMulti { a, b } => (a, b,),
+                        other => panic!("called unwrap_named_multi on a {:?} value", other.kind()),
+                    }
+                }
+
+                pub fn unwrap_unnamed_multi(self) -> (u32, String,) {
+                    match self {
+                        Self::UnnamedMulti(v0, v1) => (v0, v1,),
+                        other => panic!("called unwrap_unnamed_multi on a {:?} value", other.kind()),
+                    }
+                }
+
+                pub fn try_unwrap_named_multi(self) -> Result<(u32, String,), Self> {
+                    match self {
+                        Self::NamedMulti { a, b } => Ok((a, b,)),
+                        other => Err(other),
+                    }
+                }
+
+                pub fn try_unwrap_unnamed_multi(self) -> Result<(u32, String,), Self> {
+                    match self {
+                        Self::UnnamedMulti(v0, v1) => Ok((v0, v1,)),
+                        other => Err(other),
+                    }
+                }
+
+                pub fn named_multi(a: u32, b: String) -> Self {
+                    Self::NamedMulti { a, b }
+                }
+
+                pub fn unnamed_multi(v0: u32, v1: String) -> Self {
+                    Self::UnnamedMulti(v0, v1)
+                }
+            }
+        };
+
+        assert_eq!(actual_tokens.to_string(), expected_tokens.to_string());
+    }
+
+    #[test]
+    fn test_skips_from_impl_for_duplicate_inner_types() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                First(u32),
+                Second(u32),
+                Third(String),
+            }
+        };
+
+        let actual_tokens = nice_enum_impl(ast).unwrap();
+
+        let expected_tokens = quote! {
+            #[derive(Debug , Clone , Copy , PartialEq , Eq , PartialOrd , Ord , Hash)]
+            pub enum MyEnumKind {
+                First,
+                Second,
+                Third,
+            }
+
+            impl MyEnumKind {
+                pub const ALL: &'static [MyEnumKind] = &[MyEnumKind::First, MyEnumKind::Second, MyEnumKind::Third];
+
+                pub fn name(&self) -> &'static str {
+                    match self {
+                        MyEnumKind::First => "First",
+                        MyEnumKind::Second => "Second",
+                        MyEnumKind::Third => "Third",
+                    }
+                }
+            }
+
+            impl ::core::fmt::Display for MyEnumKind {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(self.name())
+                }
+            }
+
+            #[derive(Debug)]
+            pub struct MyEnumKindParseError(String);
+
+            impl ::core::fmt::Display for MyEnumKindParseError {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "unknown `{}` variant: `{}`", stringify!(MyEnumKind), self.0)
+                }
+            }
+
+            impl ::core::error::Error for MyEnumKindParseError {}
+
+            impl ::core::str::FromStr for MyEnumKind {
+                type Err = MyEnumKindParseError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        "First" => Ok(MyEnumKind::First),
+                        "Second" => Ok(MyEnumKind::Second),
+                        "Third" => Ok(MyEnumKind::Third),
+                        _ => Err(MyEnumKindParseError(s.to_string())),
+                    }
+                }
+            }
+
+            impl MyEnum {
+                pub fn kind(&self) -> MyEnumKind {
+                    match self {
+                        Self::First(..) => MyEnumKind::First,
+                        Self::Second(..) => MyEnumKind::Second,
+                        Self::Third(..) => MyEnumKind::Third,
+                    }
+                }
+
+                pub fn is_first(&self) -> bool {
+                    matches!(self.kind(), MyEnumKind::First)
+                }
+
+                pub fn is_second(&self) -> bool {
+                    matches!(self.kind(), MyEnumKind::Second)
+                }
+
+                pub fn is_third(&self) -> bool {
+                    matches!(self.kind(), MyEnumKind::Third)
+                }
+
+                pub fn as_first(&self) -> Option<&u32> {
+                    match self {
+                        Self::First(v) => Some(v),
+                        _ => None,
+                    }
+                }
+
+                pub fn as_second(&self) -> Option<&u32> {
+                    match self {
+                        Self::Second(v) => Some(v),
+                        _ => None,
+                    }
+                }
+
+                pub fn as_third(&self) -> Option<&String> {
+                    match self {
+                        Self::Third(v) => Some(v),
+                        _ => None,
+                    }
+                }
+
+                pub fn as_first_mut(&mut self) -> Option<&mut u32> {
+                    match self {
+                        Self::First(v) => Some(v),
+                        _ => None,
+                    }
+                }
+
+                pub fn as_second_mut(&mut self) -> Option<&mut u32> {
+                    match self {
+                        Self::Second(v) => Some(v),
+                        _ => None,
+                    }
+                }
+
+                pub fn as_third_mut(&mut self) -> Option<&mut String> {
+                    match self {
+                        Self::Third(v) => Some(v),
+                        _ => None,
+                    }
+                }
+
+                pub fn into_first(self) -> Option<u32> {
+                    match self {
+                        Self::First(v) => Some(v),
+                        _ => None,
+                    }
+                }
+
+                pub fn into_second(self) -> Option<u32> {
+                    match self {
+                        Self::Second(v) => Some(v),
+                        _ => None,
+                    }
+                }
+
+                pub fn into_third(self) -> Option<String> {
+                    match self {
+                        Self::Third(v) => Some(v),
+                        _ => None,
+                    }
+                }
+
+                pub fn unwrap_first(self) -> u32 {
+                    match self {
+                        Self::First(v) => v,
+                        other => panic!("called unwrap_first on a {:?} value", other.kind()),
+                    }
+                }
+
+                pub fn unwrap_second(self) -> u32 {
+                    match self {
+                        Self::Second(v) => v,
+                        other => panic!("called unwrap_second on a {:?} value", other.kind()),
+                    }
+                }
+
+                pub fn unwrap_third(self) -> String {
+                    match self {
+                        Self::Third(v) => v,
+                        other => panic!("called unwrap_third on a {:?} value", other.kind()),
+                    }
+                }
+
+                pub fn try_unwrap_first(self) -> Result<u32, Self> {
+                    match self {
+                        Self::First(v) => Ok(v),
+                        other => Err(other),
+                    }
+                }
+
+                pub fn try_unwrap_second(self) -> Result<u32, Self> {
+                    match self {
+                        Self::Second(v) => Ok(v),
+                        other => Err(other),
+                    }
+                }
+
+                pub fn try_unwrap_third(self) -> Result<String, Self> {
+                    match self {
+                        Self::Third(v) => Ok(v),
+                        other => Err(other),
+                    }
+                }
+
+                pub fn first(v: u32) -> Self {
+                    Self::First(v)
+                }
+
+                pub fn second(v: u32) -> Self {
+                    Self::Second(v)
+                }
+
+                pub fn third(v: String) -> Self {
+                    Self::Third(v)
+                }
+            }
+
+            impl ::core::convert::From<String> for MyEnum {
+                fn from(v: String) -> Self {
+                    Self::Third(v)
+                }
+            }
+        };
+
+        assert_eq!(actual_tokens.to_string(), expected_tokens.to_string());
+    }
+
+    #[test]
+    fn test_container_attrs_rename_kind_and_add_derives() {
+        let ast: DeriveInput = parse_quote! {
+            #[nice_enum(kind = "MyState", derive(serde::Serialize))]
+            pub enum MyEnum {
+                Unit,
+            }
+        };
+
+        let actual_tokens = nice_enum_impl(ast).unwrap();
+
+        let expected_tokens = quote! {
+            #[derive(Debug , Clone , Copy , PartialEq , Eq , PartialOrd , Ord , Hash , serde::Serialize)]
+            pub enum MyState {
+                Unit,
+            }
+
+            impl MyState {
+                pub const ALL: &'static [MyState] = &[MyState::Unit];
+
+                pub fn name(&self) -> &'static str {
+                    match self {
+                        MyState::Unit => "Unit",
+                    }
+                }
+            }
+
+            impl ::core::fmt::Display for MyState {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(self.name())
+                }
+            }
+
+            #[derive(Debug)]
+            pub struct MyStateParseError(String);
+
+            impl ::core::fmt::Display for MyStateParseError {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "unknown `{}` variant: `{}`", stringify!(MyState), self.0)
+                }
+            }
+
+            impl ::core::error::Error for MyStateParseError {}
+
+            impl ::core::str::FromStr for MyState {
+                type Err = MyStateParseError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        "Unit" => Ok(MyState::Unit),
+                        _ => Err(MyStateParseError(s.to_string())),
+                    }
+                }
+            }
+
+            impl MyEnum {
+                pub fn kind(&self) -> MyState {
+                    match self {
+                        Self::Unit => MyState::Unit,
+                    }
+                }
+
+                pub fn is_unit(&self) -> bool {
+                    matches!(self.kind(), MyState::Unit)
+                }
+
+                pub fn try_unwrap_unit(self) -> Result<(), Self> {
+                    match self {
+                        Self::Unit => Ok(()),
+                        other => Err(other),
+                    }
+                }
+            }
+        };
+
+        assert_eq!(actual_tokens.to_string(), expected_tokens.to_string());
+    }
+
+    #[test]
+    fn test_variant_attrs_skip_and_rename() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                #[nice_enum(skip)]
+                Hidden(u32),
+                #[nice_enum(rename = "renamed")]
+                Original(u32),
+            }
+        };
+
+        let actual_tokens = nice_enum_impl(ast).unwrap();
+
+        let expected_tokens = quote! {
+            #[derive(Debug , Clone , Copy , PartialEq , Eq , PartialOrd , Ord , Hash)]
+            pub enum MyEnumKind {
+                Hidden,
+                Original,
+            }
+
+            impl MyEnumKind {
+                pub const ALL: &'static [MyEnumKind] = &[MyEnumKind::Hidden, MyEnumKind::Original];
+
+                pub fn name(&self) -> &'static str {
+                    match self {
+                        MyEnumKind::Hidden => "Hidden",
+                        MyEnumKind::Original => "Original",
+                    }
+                }
+            }
+
+            impl ::core::fmt::Display for MyEnumKind {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(self.name())
+                }
+            }
+
+            #[derive(Debug)]
+            pub struct MyEnumKindParseError(String);
+
+            impl ::core::fmt::Display for MyEnumKindParseError {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "unknown `{}` variant: `{}`", stringify!(MyEnumKind), self.0)
+                }
+            }
+
+            impl ::core::error::Error for MyEnumKindParseError {}
+
+            impl ::core::str::FromStr for MyEnumKind {
+                type Err = MyEnumKindParseError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        "Hidden" => Ok(MyEnumKind::Hidden),
+                        "Original" => Ok(MyEnumKind::Original),
+                        _ => Err(MyEnumKindParseError(s.to_string())),
+                    }
+                }
+            }
+
+            impl MyEnum {
+                pub fn kind(&self) -> MyEnumKind {
+                    match self {
+                        Self::Hidden(..) => MyEnumKind::Hidden,
+                        Self::Original(..) => MyEnumKind::Original,
+                    }
+                }
+
+                pub fn is_renamed(&self) -> bool {
+                    matches!(self.kind(), MyEnumKind::Original)
+                }
+
+                pub fn as_renamed(&self) -> Option<&u32> {
+                    match self {
+                        Self::Original(v) => Some(v),
+                        _ => None,
+                    }
+                }
+
+                pub fn as_renamed_mut(&mut self) -> Option<&mut u32> {
+                    match self {
+                        Self::Original(v) => Some(v),
+                        _ => None,
+                    }
+                }
+
+                pub fn into_renamed(self) -> Option<u32> {
+                    match self {
+                        Self::Original(v) => Some(v),
+                        _ => None,
+                    }
+                }
+
+                pub fn unwrap_renamed(self) -> u32 {
+                    match self {
+                        Self::Original(v) => v,
+                        other => panic!("called unwrap_renamed on a {:?} value", other.kind()),
+                    }
+                }
+
+                pub fn try_unwrap_renamed(self) -> Result<u32, Self> {
+                    match self {
+                        Self::Original(v) => Ok(v),
+                        other => Err(other),
+                    }
+                }
+
+                pub fn renamed(v: u32) -> Self {
+                    Self::Original(v)
+                }
+            }
+
+            impl ::core::convert::From<u32> for MyEnum {
+                fn from(v: u32) -> Self {
+                    Self::Original(v)
+                }
+            }
+        };
+
+        assert_eq!(actual_tokens.to_string(), expected_tokens.to_string());
+    }
+
+    #[test]
+    fn test_kind_enum_is_reflective() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                Unit,
+                NamedFields { a: u32 },
+            }
+        };
+
+        let actual_tokens = nice_enum_impl(ast).unwrap();
+
+        let expected_tokens = quote! {
+            #[derive(Debug , Clone , Copy , PartialEq , Eq , PartialOrd , Ord , Hash)]
+            pub enum MyEnumKind {
+                Unit,
+                NamedFields,
+            }
+
+            impl MyEnumKind {
+                pub const ALL: &'static [MyEnumKind] = &[MyEnumKind::Unit, MyEnumKind::NamedFields];
+
+                pub fn name(&self) -> &'static str {
+                    match self {
+                        MyEnumKind::Unit => "Unit",
+                        MyEnumKind::NamedFields => "NamedFields",
+                    }
+                }
+            }
+
+            impl ::core::fmt::Display for MyEnumKind {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(self.name())
+                }
+            }
+
+            #[derive(Debug)]
+            pub struct MyEnumKindParseError(String);
+
+            impl ::core::fmt::Display for MyEnumKindParseError {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "unknown `{}` variant: `{}`", stringify!(MyEnumKind), self.0)
+                }
+            }
+
+            impl ::core::error::Error for MyEnumKindParseError {}
+
+            impl ::core::str::FromStr for MyEnumKind {
+                type Err = MyEnumKindParseError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        "Unit" => Ok(MyEnumKind::Unit),
+                        "NamedFields" => Ok(MyEnumKind::NamedFields),
+                        _ => Err(MyEnumKindParseError(s.to_string())),
+                    }
+                }
+            }
+
+            impl MyEnum {
+                pub fn kind(&self) -> MyEnumKind {
+                    match self {
+                        Self::Unit => MyEnumKind::Unit,
+                        Self::NamedFields { .. } => MyEnumKind::NamedFields,
+                    }
+                }
+
+                pub fn is_unit(&self) -> bool {
+                    matches!(self.kind(), MyEnumKind::Unit)
+                }
+
+                pub fn is_named_fields(&self) -> bool {
+                    matches!(self.kind(), MyEnumKind::NamedFields)
+                }
+
+                pub fn as_named_fields(&self) -> Option<&u32> {
+                    match self {
+                        Self::NamedFields { a } => Some(a),
+                        _ => None,
+                    }
+                }
+
+                pub fn as_named_fields_mut(&mut self) -> Option<&mut u32> {
+                    match self {
+                        Self::NamedFields { a } => Some(a),
+                        _ => None,
+                    }
+                }
+
+                pub fn into_named_fields(self) -> Option<u32> {
+                    match self {
+                        Self::NamedFields { a } => Some(a),
+                        _ => None,
+                    }
+                }
+
+                pub fn unwrap_named_fields(self) -> u32 {
+                    match self {
+                        Self::NamedFields { a } => a,
+                        other => panic!("called unwrap_named_fields on a {:?} value", other.kind()),
+                    }
+                }
+
+                pub fn try_unwrap_unit(self) -> Result<(), Self> {
+                    match self {
+                        Self::Unit => Ok(()),
+                        other => Err(other),
+                    }
+                }
+
+                pub fn try_unwrap_named_fields(self) -> Result<u32, Self> {
+                    match self {
+                        Self::NamedFields { a } => Ok(a),
+                        other => Err(other),
+                    }
+                }
+
+                pub fn named_fields(a: u32) -> Self {
+                    Self::NamedFields { a }
+                }
+            }
+        };
+
+        assert_eq!(actual_tokens.to_string(), expected_tokens.to_string());
+    }
+
+    #[test]
+    fn test_unknown_container_attr_is_a_compile_error() {
+        let ast: DeriveInput = parse_quote! {
+            #[nice_enum(bogus = "oops")]
+            pub enum MyEnum {
+                Unit,
+            }
+        };
+
+        let err = nice_enum_impl(ast).unwrap_err();
+        assert!(err.to_string().contains("unknown `nice_enum` attribute"));
+    }
 }